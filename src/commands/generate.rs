@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use crate::commands::validate_worker_name;
@@ -6,6 +6,46 @@ use crate::settings::target::{Manifest, Site, TargetType};
 use crate::terminal::{emoji, message};
 use crate::{commands, install};
 
+// Built-in templates that can be referenced by name instead of a full git URL,
+// e.g. `wrangler generate my-worker rust`.
+const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "rust",
+        "https://github.com/cloudflare/rustwasm-worker-template",
+    ),
+    (
+        "javascript",
+        "https://github.com/cloudflare/worker-template",
+    ),
+    (
+        "webpack",
+        "https://github.com/cloudflare/worker-template-webpack",
+    ),
+];
+
+// How a template argument should be passed along to cargo-generate.
+#[derive(Debug, PartialEq)]
+enum TemplateSource {
+    Git(String),
+    Path(String),
+}
+
+fn resolve_template(template: &str) -> TemplateSource {
+    // An existing local path always wins, even if it happens to share a name
+    // with a built-in template (e.g. a local `./rust` directory) -- a user
+    // who has a directory by that name clearly means to use it, not have it
+    // silently resolve to the remote built-in instead.
+    if Path::new(template).exists() {
+        return TemplateSource::Path(template.to_string());
+    }
+
+    if let Some((_, url)) = BUILTIN_TEMPLATES.iter().find(|(name, _)| *name == template) {
+        return TemplateSource::Git((*url).to_string());
+    }
+
+    TemplateSource::Git(template.to_string())
+}
+
 pub fn generate(
     name: &str,
     template: &str,
@@ -31,7 +71,19 @@ pub fn run_generate(name: &str, template: &str) -> Result<(), failure::Error> {
     let tool_name = "cargo-generate";
     let binary_path = install::install(tool_name, "ashleygwilliams")?.binary(tool_name)?;
 
-    let args = ["generate", "--git", template, "--name", name, "--force"];
+    let (template_flag, template_arg) = match resolve_template(template) {
+        TemplateSource::Git(url) => ("--git", url),
+        TemplateSource::Path(path) => ("--path", path),
+    };
+
+    let args = [
+        "generate",
+        template_flag,
+        &template_arg,
+        "--name",
+        name,
+        "--force",
+    ];
 
     let command = command(name, binary_path, &args);
     let command_name = format!("{:?}", command);
@@ -61,3 +113,55 @@ fn command(name: &str, binary_path: PathBuf, args: &[&str]) -> Command {
     c.args(args);
     c
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_template, TemplateSource};
+
+    #[test]
+    fn it_resolves_builtin_templates_to_their_git_url() {
+        assert_eq!(
+            resolve_template("rust"),
+            TemplateSource::Git("https://github.com/cloudflare/rustwasm-worker-template".into())
+        );
+    }
+
+    #[test]
+    fn it_resolves_an_existing_path_to_a_path_source() {
+        let existing_dir = std::env::temp_dir();
+        let existing_dir = existing_dir.to_str().unwrap();
+        assert_eq!(
+            resolve_template(existing_dir),
+            TemplateSource::Path(existing_dir.to_string())
+        );
+    }
+
+    #[test]
+    fn it_falls_back_to_git_for_unknown_templates() {
+        assert_eq!(
+            resolve_template("https://github.com/someuser/some-template"),
+            TemplateSource::Git("https://github.com/someuser/some-template".into())
+        );
+    }
+
+    // Reproduces the actual collision: `wrangler generate my-app rust` run
+    // from a directory that has a local `./rust` template checked out. This
+    // mutates the process's cwd for the duration of the test and restores it
+    // afterwards.
+    #[test]
+    fn it_prefers_a_local_directory_over_a_same_named_builtin() {
+        let scratch_dir = std::env::temp_dir().join("wrangler_test_template_collision");
+        let _ = std::fs::remove_dir_all(&scratch_dir);
+        std::fs::create_dir_all(scratch_dir.join("rust")).unwrap();
+
+        let original_cwd = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&scratch_dir).unwrap();
+
+        let result = resolve_template("rust");
+
+        std::env::set_current_dir(original_cwd).unwrap();
+        std::fs::remove_dir_all(&scratch_dir).unwrap();
+
+        assert_eq!(result, TemplateSource::Path("rust".to_string()));
+    }
+}
@@ -0,0 +1,49 @@
+use cloudflare::endpoints::workerskv::delete_bulk::DeleteBulk;
+use cloudflare::framework::apiclient::ApiClient;
+
+use crate::commands::kv::{api_client, confirm_delete, format_error, get_namespace_id};
+use crate::settings::global_user::GlobalUser;
+use crate::settings::target::Target;
+use crate::terminal::message;
+
+// Removes keys from a Workers Sites asset bucket that no longer correspond to
+// a file on disk, so stale assets don't linger in the namespace after a sync.
+pub fn delete_stale(
+    target: &Target,
+    user: &GlobalUser,
+    binding: &str,
+    stale_keys: &[String],
+    force: bool,
+) -> Result<(), failure::Error> {
+    if stale_keys.is_empty() {
+        return Ok(());
+    }
+
+    let namespace_id = get_namespace_id(target, binding)?;
+
+    let prompt_string = format!(
+        "Are you sure you want to delete the following {} stale key(s) from your site's namespace?\n{}",
+        stale_keys.len(),
+        stale_keys.join("\n")
+    );
+    if !confirm_delete(&prompt_string, force)? {
+        message::info("Not deleting");
+        return Ok(());
+    }
+
+    let client = api_client(user)?;
+
+    let response = client.request(&DeleteBulk {
+        account_identifier: &target.account_id,
+        namespace_identifier: &namespace_id,
+        bulk_keys: stale_keys.to_vec(),
+    });
+
+    match response {
+        Ok(_) => {
+            message::success(&format!("Deleted {} stale key(s)", stale_keys.len()));
+            Ok(())
+        }
+        Err(e) => failure::bail!("{}", format_error(e)),
+    }
+}
@@ -0,0 +1,331 @@
+use std::fs;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use cloudflare::endpoints::workerskv::list_namespace_keys::{
+    ListNamespaceKeys, ListNamespaceKeysParams,
+};
+use cloudflare::endpoints::workerskv::read_key::ReadKey;
+use cloudflare::endpoints::workerskv::write_bulk::{KeyValuePair, WriteBulk};
+use cloudflare::framework::apiclient::ApiClient;
+
+use crate::commands::kv::{api_client, format_error, get_namespace_id, url_encode_key};
+use crate::settings::global_user::GlobalUser;
+use crate::settings::target::Target;
+use crate::terminal::message;
+
+// The maximum number of pairs that can be sent in a single bulk write request.
+// https://api.cloudflare.com/#workers-kv-namespace-write-multiple-key-value-pairs
+const KEY_VALUE_PAIR_LIMIT: usize = 10000;
+
+pub fn put(
+    target: &Target,
+    user: &GlobalUser,
+    binding: &str,
+    filename: &Path,
+) -> Result<(), failure::Error> {
+    let namespace_id = get_namespace_id(target, binding)?;
+    let client = api_client(user)?;
+
+    let pairs: Vec<KeyValuePair> = serde_json::from_str(&fs::read_to_string(filename)?)?;
+
+    message::working(&format!("Writing {} key-value pairs...", pairs.len()));
+
+    for chunk in pairs.chunks(KEY_VALUE_PAIR_LIMIT) {
+        let response = client.request(&WriteBulk {
+            account_identifier: &target.account_id,
+            namespace_identifier: &namespace_id,
+            bulk_key_value_pairs: chunk.to_vec(),
+        });
+
+        match response {
+            Ok(_) => {}
+            Err(e) => failure::bail!("{}", format_error(e)),
+        }
+    }
+
+    message::success("Success");
+    Ok(())
+}
+
+// Fetches every key in a namespace (paginating through the list cursor) and
+// streams the key-value pairs to `filename` as a JSON array, so the whole
+// namespace never has to be held in memory at once.
+pub fn download(
+    target: &Target,
+    user: &GlobalUser,
+    binding: &str,
+    filename: &Path,
+) -> Result<(), failure::Error> {
+    let namespace_id = get_namespace_id(target, binding)?;
+    let client = api_client(user)?;
+
+    download_to(
+        filename,
+        |cursor| list_page(&client, target, &namespace_id, cursor),
+        |key| fetch_value(&client, target, &namespace_id, key),
+    )?;
+
+    message::success(&format!("Downloaded namespace to {}", filename.display()));
+    Ok(())
+}
+
+// Writes every key-value pair produced by `list_page`/`fetch_value` to
+// `filename` as a JSON array. The export is written to a temp file
+// alongside `filename` and only renamed into place once streaming succeeds;
+// if streaming fails, or the rename itself fails, the temp file is cleaned
+// up rather than left behind, so a transient error partway through a large
+// namespace can't leave a truncated (or orphaned) file on disk.
+//
+// Kept free of any HTTP client so the pagination/streaming logic can be
+// exercised directly in tests with fake `list_page`/`fetch_value` closures.
+fn download_to(
+    filename: &Path,
+    list_page: impl FnMut(Option<String>) -> Result<(Vec<String>, Option<String>), failure::Error>,
+    fetch_value: impl FnMut(&str) -> Result<String, failure::Error>,
+) -> Result<(), failure::Error> {
+    let tmp_path = PathBuf::from(format!("{}.tmp", filename.display()));
+
+    if let Err(e) = write_pairs(&tmp_path, list_page, fetch_value) {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e);
+    }
+
+    if let Err(e) = fs::rename(&tmp_path, filename) {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e.into());
+    }
+
+    Ok(())
+}
+
+fn write_pairs(
+    tmp_path: &Path,
+    list_page: impl FnMut(Option<String>) -> Result<(Vec<String>, Option<String>), failure::Error>,
+    fetch_value: impl FnMut(&str) -> Result<String, failure::Error>,
+) -> Result<(), failure::Error> {
+    let file = File::create(tmp_path)?;
+    let mut writer = BufWriter::new(file);
+    stream_pairs(&mut writer, list_page, fetch_value)
+}
+
+// Paginates through `list_page` (fetching each key's value via `fetch_value`)
+// and writes the results to `writer` as a JSON array of `{key, value}`
+// objects. Stops once a page reports no further cursor, the cursor comes
+// back as an empty string (the API's other way of signalling "no more
+// pages"), or a page returns no keys at all — any one of those alone is
+// enough to end pagination, so a client that surfaces "done" differently
+// can't spin the loop forever.
+fn stream_pairs<W: Write>(
+    writer: &mut W,
+    mut list_page: impl FnMut(Option<String>) -> Result<(Vec<String>, Option<String>), failure::Error>,
+    mut fetch_value: impl FnMut(&str) -> Result<String, failure::Error>,
+) -> Result<(), failure::Error> {
+    writer.write_all(b"[")?;
+
+    let mut cursor: Option<String> = None;
+    let mut wrote_first = false;
+
+    loop {
+        let (keys, next_cursor) = list_page(cursor)?;
+
+        for key in &keys {
+            let value = fetch_value(key)?;
+
+            if wrote_first {
+                writer.write_all(b",")?;
+            }
+            wrote_first = true;
+
+            serde_json::to_writer(
+                &mut *writer,
+                &DownloadedPair {
+                    key: key.clone(),
+                    value,
+                },
+            )?;
+        }
+
+        let no_more_keys = keys.is_empty();
+        cursor = next_cursor.filter(|c| !c.is_empty());
+        if cursor.is_none() || no_more_keys {
+            break;
+        }
+    }
+
+    writer.write_all(b"]")?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn list_page(
+    client: &impl ApiClient,
+    target: &Target,
+    namespace_id: &str,
+    cursor: Option<String>,
+) -> Result<(Vec<String>, Option<String>), failure::Error> {
+    let response = client.request(&ListNamespaceKeys {
+        account_identifier: &target.account_id,
+        namespace_identifier: namespace_id,
+        params: ListNamespaceKeysParams {
+            limit: None,
+            cursor,
+            prefix: None,
+        },
+    });
+
+    match response {
+        Ok(success) => {
+            let keys = success.result.keys.into_iter().map(|k| k.name).collect();
+            Ok((keys, success.result.cursor))
+        }
+        Err(e) => failure::bail!("{}", format_error(e)),
+    }
+}
+
+fn fetch_value(
+    client: &impl ApiClient,
+    target: &Target,
+    namespace_id: &str,
+    key: &str,
+) -> Result<String, failure::Error> {
+    let response = client.request(&ReadKey {
+        account_identifier: &target.account_id,
+        namespace_identifier: namespace_id,
+        key: &url_encode_key(key),
+    });
+
+    match response {
+        Ok(success) => Ok(success.result),
+        Err(e) => failure::bail!("{}", format_error(e)),
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DownloadedPair {
+    key: String,
+    value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::download_to;
+    use std::cell::RefCell;
+    use std::fs;
+
+    #[test]
+    fn it_paginates_and_writes_a_json_array_of_pairs() {
+        let dir = std::env::temp_dir().join("wrangler_test_bulk_download_pagination");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let filename = dir.join("out.json");
+
+        // Two pages: the first hands back a cursor, the second returns no
+        // cursor at all, ending pagination.
+        let pages = RefCell::new(vec![
+            (
+                vec!["a".to_string(), "b".to_string()],
+                Some("next".to_string()),
+            ),
+            (vec!["c".to_string()], None),
+        ]);
+
+        download_to(
+            &filename,
+            |_cursor| Ok(pages.borrow_mut().remove(0)),
+            |key| Ok(format!("value-of-{}", key)),
+        )
+        .unwrap();
+
+        let written = fs::read_to_string(&filename).unwrap();
+        assert_eq!(
+            written,
+            r#"[{"key":"a","value":"value-of-a"},{"key":"b","value":"value-of-b"},{"key":"c","value":"value-of-c"}]"#
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn it_stops_when_the_cursor_comes_back_empty() {
+        let dir = std::env::temp_dir().join("wrangler_test_bulk_download_empty_cursor");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let filename = dir.join("out.json");
+
+        // A cursor of Some("") is the API's other way of saying "no more
+        // pages" -- if we looped on it we'd call list_page a second time
+        // and this test would panic on the empty Vec.
+        let pages = RefCell::new(vec![(vec!["only".to_string()], Some(String::new()))]);
+
+        download_to(
+            &filename,
+            |_cursor| Ok(pages.borrow_mut().remove(0)),
+            |key| Ok(format!("value-of-{}", key)),
+        )
+        .unwrap();
+
+        let written = fs::read_to_string(&filename).unwrap();
+        assert_eq!(written, r#"[{"key":"only","value":"value-of-only"}]"#);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn it_cleans_up_the_temp_file_on_a_mid_stream_error() {
+        let dir = std::env::temp_dir().join("wrangler_test_bulk_download_mid_stream_error");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let filename = dir.join("out.json");
+        fs::write(&filename, "previous contents").unwrap();
+
+        let pages = RefCell::new(vec![(vec!["a".to_string(), "b".to_string()], None)]);
+
+        let result = download_to(
+            &filename,
+            |_cursor| Ok(pages.borrow_mut().remove(0)),
+            |key| {
+                if key == "b" {
+                    failure::bail!("simulated fetch failure")
+                }
+                Ok(format!("value-of-{}", key))
+            },
+        );
+
+        assert!(result.is_err());
+        let tmp_path = dir.join("out.json.tmp");
+        assert!(!tmp_path.exists());
+        // The pre-existing destination file must be untouched.
+        assert_eq!(fs::read_to_string(&filename).unwrap(), "previous contents");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn it_cleans_up_the_temp_file_when_the_final_rename_fails() {
+        let dir = std::env::temp_dir().join("wrangler_test_bulk_download_rename_failure");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        // A directory can't be the target of a file rename, so this forces
+        // the rename step (not the write step) to fail.
+        let filename = dir.join("out.json");
+        fs::create_dir_all(&filename).unwrap();
+
+        let pages = RefCell::new(vec![(vec!["a".to_string()], None)]);
+
+        let result = download_to(
+            &filename,
+            |_cursor| Ok(pages.borrow_mut().remove(0)),
+            |key| Ok(format!("value-of-{}", key)),
+        );
+
+        assert!(result.is_err());
+        let tmp_path = dir.join("out.json.tmp");
+        assert!(!tmp_path.exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,178 @@
+use cloudflare::endpoints::workerskv::delete_key::DeleteKey;
+use cloudflare::endpoints::workerskv::read_key::ReadKey;
+use cloudflare::endpoints::workerskv::read_key_metadata::ReadKeyMetadata;
+use cloudflare::endpoints::workerskv::write_key::{WriteKey, WriteKeyBody};
+use cloudflare::framework::apiclient::ApiClient;
+
+use crate::commands::kv::{
+    api_client, confirm_delete, format_error, get_namespace_id, url_encode_key,
+};
+use crate::settings::global_user::GlobalUser;
+use crate::settings::target::Target;
+use crate::terminal::message;
+
+pub fn put(
+    target: &Target,
+    user: &GlobalUser,
+    binding: &str,
+    key: &str,
+    value: &str,
+    expiration: Option<u64>,
+    expiration_ttl: Option<u64>,
+    metadata: Option<serde_json::Value>,
+) -> Result<(), failure::Error> {
+    let namespace_id = get_namespace_id(target, binding)?;
+    let client = api_client(user)?;
+
+    let response = client.request(&WriteKey {
+        account_identifier: &target.account_id,
+        namespace_identifier: &namespace_id,
+        key: &url_encode_key(key),
+        body: build_write_body(value, expiration, expiration_ttl, metadata),
+    });
+
+    match response {
+        Ok(_) => {
+            message::success(&format!("Successfully wrote key \"{}\"", key));
+            Ok(())
+        }
+        Err(e) => failure::bail!("{}", format_error(e)),
+    }
+}
+
+// Passes `expiration`/`expiration_ttl`/`metadata` through to the write body
+// untouched (including both being absent), so the caller's choice of
+// lifetime/metadata is exactly what reaches the API.
+fn build_write_body(
+    value: &str,
+    expiration: Option<u64>,
+    expiration_ttl: Option<u64>,
+    metadata: Option<serde_json::Value>,
+) -> WriteKeyBody {
+    WriteKeyBody {
+        value: value.to_string(),
+        expiration,
+        expiration_ttl,
+        metadata,
+    }
+}
+
+pub fn get(
+    target: &Target,
+    user: &GlobalUser,
+    binding: &str,
+    key: &str,
+    metadata: bool,
+) -> Result<(), failure::Error> {
+    let namespace_id = get_namespace_id(target, binding)?;
+    let client = api_client(user)?;
+
+    let response = client.request(&ReadKey {
+        account_identifier: &target.account_id,
+        namespace_identifier: &namespace_id,
+        key: &url_encode_key(key),
+    });
+
+    let value = match response {
+        Ok(success) => success.result,
+        Err(e) => failure::bail!("{}", format_error(e)),
+    };
+
+    if !metadata {
+        println!("{}", value);
+        return Ok(());
+    }
+
+    let response = client.request(&ReadKeyMetadata {
+        account_identifier: &target.account_id,
+        namespace_identifier: &namespace_id,
+        key: &url_encode_key(key),
+    });
+
+    let metadata = match response {
+        Ok(success) => success.result,
+        Err(e) => failure::bail!("{}", format_error(e)),
+    };
+
+    println!("{}", combine_value_and_metadata(&value, metadata));
+
+    Ok(())
+}
+
+// The combined shape printed by `kv:key get --metadata`.
+fn combine_value_and_metadata(value: &str, metadata: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "value": value,
+        "metadata": metadata,
+    })
+}
+
+pub fn delete(
+    target: &Target,
+    user: &GlobalUser,
+    binding: &str,
+    key: &str,
+    force: bool,
+) -> Result<(), failure::Error> {
+    let namespace_id = get_namespace_id(target, binding)?;
+
+    let prompt_string = format!("Are you sure you want to delete key \"{}\"?", key);
+    if !confirm_delete(&prompt_string, force)? {
+        message::info("Not deleting");
+        return Ok(());
+    }
+
+    let client = api_client(user)?;
+
+    let response = client.request(&DeleteKey {
+        account_identifier: &target.account_id,
+        namespace_identifier: &namespace_id,
+        key: &url_encode_key(key),
+    });
+
+    match response {
+        Ok(_) => {
+            message::success(&format!("Successfully deleted key \"{}\"", key));
+            Ok(())
+        }
+        Err(e) => failure::bail!("{}", format_error(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_write_body, combine_value_and_metadata};
+
+    #[test]
+    fn it_passes_through_no_expiration_or_metadata() {
+        let body = build_write_body("hello", None, None, None);
+        assert_eq!(body.value, "hello");
+        assert_eq!(body.expiration, None);
+        assert_eq!(body.expiration_ttl, None);
+        assert_eq!(body.metadata, None);
+    }
+
+    #[test]
+    fn it_passes_through_expiration_and_ttl_as_given() {
+        let body = build_write_body("hello", Some(1000), Some(60), None);
+        assert_eq!(body.expiration, Some(1000));
+        assert_eq!(body.expiration_ttl, Some(60));
+    }
+
+    #[test]
+    fn it_passes_through_metadata_unchanged() {
+        let metadata = serde_json::json!({"someKey": "someValue"});
+        let body = build_write_body("hello", None, None, Some(metadata.clone()));
+        assert_eq!(body.metadata, Some(metadata));
+    }
+
+    #[test]
+    fn it_combines_value_and_metadata_for_get() {
+        let metadata = serde_json::json!({"someKey": "someValue"});
+        let combined = combine_value_and_metadata("hello", metadata.clone());
+        assert_eq!(
+            combined,
+            serde_json::json!({"value": "hello", "metadata": metadata})
+        );
+    }
+}
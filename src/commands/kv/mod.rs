@@ -104,7 +104,11 @@ fn format_error(e: ApiFailure) -> String {
 fn interactive_delete(prompt_string: &str) -> Result<bool, failure::Error> {
     println!("{} [y/n]", prompt_string);
     let mut response: String = read!("{}\n");
-    response = response.split_whitespace().collect(); // remove whitespace
+    parse_delete_response(&response)
+}
+
+fn parse_delete_response(response: &str) -> Result<bool, failure::Error> {
+    let mut response: String = response.split_whitespace().collect(); // remove whitespace
     response.make_ascii_lowercase(); // ensure response is all lowercase
     response.truncate(INTERACTIVE_RESPONSE_LEN); // at this point, all valid input will be "y" or "n"
     match response.as_ref() {
@@ -114,6 +118,25 @@ fn interactive_delete(prompt_string: &str) -> Result<bool, failure::Error> {
     }
 }
 
+// Confirms a delete, either by prompting a human on a tty, or for `--force`/CI
+// use, by assuming the answer is yes. Used by every KV delete path (key,
+// namespace, bucket) so automated pipelines aren't blocked on stdin.
+//
+// A missing tty alone is not enough to skip the prompt: that would silently
+// turn any non-interactive invocation (an IDE task runner, `docker exec`
+// without `-it`, stdin redirected for unrelated reasons) into a confirmed
+// delete. Non-interactive runs must say so explicitly, either via `CI` or
+// `--force`.
+pub fn confirm_delete(prompt_string: &str, force: bool) -> Result<bool, failure::Error> {
+    if force || std::env::var("CI").is_ok() {
+        return Ok(true);
+    }
+    if !atty::is(atty::Stream::Stdin) {
+        failure::bail!("Not running interactively; pass --force to delete without confirmation")
+    }
+    interactive_delete(prompt_string)
+}
+
 fn url_encode_key(key: &str) -> String {
     percent_encode(key.as_bytes(), PATH_SEGMENT_ENCODE_SET).to_string()
 }
@@ -203,4 +226,28 @@ mod tests {
         };
         assert!(kv::get_namespace_id(&target_with_dup_kv_bindings, "").is_err());
     }
+
+    #[test]
+    fn it_parses_yes_responses() {
+        assert!(kv::parse_delete_response("y").unwrap());
+        assert!(kv::parse_delete_response("Yes").unwrap());
+        assert!(kv::parse_delete_response("  YES  ").unwrap());
+    }
+
+    #[test]
+    fn it_parses_no_responses() {
+        assert!(!kv::parse_delete_response("n").unwrap());
+        assert!(!kv::parse_delete_response("No").unwrap());
+    }
+
+    #[test]
+    fn it_rejects_garbage_responses() {
+        assert!(kv::parse_delete_response("maybe").is_err());
+        assert!(kv::parse_delete_response("").is_err());
+    }
+
+    #[test]
+    fn it_skips_prompt_when_forced() {
+        assert!(kv::confirm_delete("delete?", true).unwrap());
+    }
 }
@@ -0,0 +1,76 @@
+use cloudflare::endpoints::workerskv::create_namespace::CreateNamespace;
+use cloudflare::endpoints::workerskv::list_namespaces::ListNamespaces;
+use cloudflare::endpoints::workerskv::remove_namespace::RemoveNamespace;
+use cloudflare::framework::apiclient::ApiClient;
+
+use crate::commands::kv::{api_client, confirm_delete, format_error};
+use crate::settings::global_user::GlobalUser;
+use crate::settings::target::Target;
+use crate::terminal::message;
+
+pub fn create(target: &Target, user: &GlobalUser, title: &str) -> Result<(), failure::Error> {
+    let client = api_client(user)?;
+
+    let response = client.request(&CreateNamespace {
+        account_identifier: &target.account_id,
+        title,
+    });
+
+    match response {
+        Ok(success) => {
+            message::success(&format!(
+                "Created namespace \"{}\" with id \"{}\"",
+                title, success.result.id
+            ));
+            Ok(())
+        }
+        Err(e) => failure::bail!("{}", format_error(e)),
+    }
+}
+
+pub fn list(target: &Target, user: &GlobalUser) -> Result<(), failure::Error> {
+    let client = api_client(user)?;
+
+    let response = client.request(&ListNamespaces {
+        account_identifier: &target.account_id,
+    });
+
+    match response {
+        Ok(success) => {
+            println!("{}", serde_json::to_string(&success.result)?);
+            Ok(())
+        }
+        Err(e) => failure::bail!("{}", format_error(e)),
+    }
+}
+
+pub fn delete(
+    target: &Target,
+    user: &GlobalUser,
+    namespace_id: &str,
+    force: bool,
+) -> Result<(), failure::Error> {
+    let prompt_string = format!(
+        "Are you sure you want to delete namespace \"{}\"?",
+        namespace_id
+    );
+    if !confirm_delete(&prompt_string, force)? {
+        message::info("Not deleting");
+        return Ok(());
+    }
+
+    let client = api_client(user)?;
+
+    let response = client.request(&RemoveNamespace {
+        account_identifier: &target.account_id,
+        namespace_identifier: namespace_id,
+    });
+
+    match response {
+        Ok(_) => {
+            message::success(&format!("Deleted namespace \"{}\"", namespace_id));
+            Ok(())
+        }
+        Err(e) => failure::bail!("{}", format_error(e)),
+    }
+}